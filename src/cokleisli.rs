@@ -0,0 +1,94 @@
+//! Co-Kleisli composition: chaining `extend`-style arrows end to end.
+//!
+//! `extend` runs one co-Kleisli arrow `C<A> -> B` at every focus of a
+//! comonad. Without a composition combinator, chaining two such arrows
+//! means calling `extend` twice and threading the intermediate comonad by
+//! hand; [`compose_cokleisli`] (and the [`CoKleisli`] operator wrapper)
+//! do that threading for you.
+
+use std::ops::Shr;
+use std::rc::Rc;
+
+use crate::comonad::Comonad;
+
+/// Compose two co-Kleisli arrows `g: C<A> -> B` and `h: C<B> -> C`, producing
+/// `C<A> -> C` via `|w| h(w.extend(g))`.
+pub fn compose_cokleisli<W, B, C>(
+    g: impl Fn(W) -> B + Clone + 'static,
+    h: impl Fn(W::Duplicated<B>) -> C + 'static,
+) -> impl Fn(W) -> C
+where
+    W: Comonad,
+    B: Clone + 'static,
+{
+    move |w: W| h(w.extend(g.clone()))
+}
+
+/// A co-Kleisli arrow `C<A> -> B`, wrapped so arrows can be chained with `>>`
+/// instead of calling [`compose_cokleisli`] directly.
+pub struct CoKleisli<W, B>(Rc<dyn Fn(W) -> B>);
+
+impl<W, B> Clone for CoKleisli<W, B> {
+    fn clone(&self) -> Self {
+        CoKleisli(self.0.clone())
+    }
+}
+
+impl<W, B> CoKleisli<W, B> {
+    pub fn new(f: impl Fn(W) -> B + 'static) -> Self {
+        CoKleisli(Rc::new(f))
+    }
+
+    pub fn run(&self, w: W) -> B {
+        (self.0)(w)
+    }
+}
+
+/// `g >> h` runs `g` at every focus via `extend`, then feeds the result to `h`.
+impl<W, B, C> Shr<CoKleisli<W::Duplicated<B>, C>> for CoKleisli<W, B>
+where
+    W: Comonad,
+    B: Clone + 'static,
+    C: 'static,
+{
+    type Output = CoKleisli<W, C>;
+
+    fn shr(self, rhs: CoKleisli<W::Duplicated<B>, C>) -> Self::Output {
+        CoKleisli::new(move |w: W| {
+            let g = self.clone();
+            rhs.run(w.extend(move |x: W| g.run(x)))
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::env::Env;
+
+    fn g(w: Env<i32, i32>) -> i32 {
+        w.value + w.env
+    }
+
+    fn h(w: Env<i32, i32>) -> i32 {
+        w.value * 2
+    }
+
+    #[test]
+    fn compose_cokleisli_matches_manual_extend() {
+        let w = Env { value: 3, env: 4 };
+        let expected = h(w.clone().extend(g));
+
+        let composed = compose_cokleisli(g, h);
+        assert_eq!(composed(w), expected);
+    }
+
+    #[test]
+    fn cokleisli_operator_matches_manual_extend() {
+        let w = Env { value: 3, env: 4 };
+        let expected = h(w.clone().extend(g));
+
+        let chained = CoKleisli::new(g) >> CoKleisli::new(h);
+        assert_eq!(chained.run(w), expected);
+    }
+}
@@ -0,0 +1,35 @@
+//! The core [`Comonad`] abstraction.
+//!
+//! A comonad is the categorical dual of a monad: instead of injecting a
+//! value into a context and sequencing context-producing computations
+//! (`bind`), a comonad lets you pull a value back out of a context
+//! ([`Comonad::extract`]) and run a context-aware computation at every
+//! position inside the context ([`Comonad::extend`]).
+
+/// A type that can be "extracted" from and "extended" over.
+///
+/// Implementors are expected to satisfy the three comonad laws:
+/// - `w.extend(Comonad::extract) == w` (right identity)
+/// - `w.extend(f).extract() == f(w)` (left identity)
+/// - `w.extend(f).extend(g) == w.extend(|w| g(w.extend(&f)))` (associativity)
+pub trait Comonad: Sized + Clone + 'static {
+    /// The type of value held at the focus of this comonad.
+    type Extracted: Clone;
+
+    /// The comonad of `B`s produced by [`Comonad::duplicate`]/[`Comonad::extend`].
+    type Duplicated<B: Clone + 'static>: Comonad<Extracted = B>;
+
+    /// extract : C(x) -> x
+    fn extract(&self) -> Self::Extracted;
+
+    /// duplicate : C(x) -> C(C(x))
+    fn duplicate(self) -> Self::Duplicated<Self>;
+
+    /// extend : (C(x) -> y) -> C(x) -> C(y)
+    fn extend<B: Clone + 'static>(self, f: impl Fn(Self) -> B + 'static) -> Self::Duplicated<B>;
+
+    /// fmap : (x -> y) -> C(x) -> C(y), derived from [`Comonad::extend`].
+    fn fmap<B: Clone + 'static>(self, f: impl Fn(Self::Extracted) -> B + 'static) -> Self::Duplicated<B> {
+        self.extend(move |w| f(w.extract()))
+    }
+}
@@ -0,0 +1,44 @@
+//! The environment (a.k.a. product) comonad: a value paired with a
+//! read-only environment. This is the comonad the crate started out with,
+//! now implemented in terms of [`Comonad`].
+
+use crate::comonad::Comonad;
+use crate::scoped_env;
+
+/// A value of type `T` paired with an environment of type `V`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Env<V: Clone, T: Clone> {
+    pub value: T,
+    pub env: V,
+}
+
+impl<V: Clone + 'static, T: Clone> Env<V, T> {
+    /// Build an `Env` by pulling its `env` field from the active
+    /// [`scoped_env::with_env`] scope, rather than a global mutable static.
+    pub fn from_scope(value: T) -> Self {
+        Env { value, env: scoped_env::current_env::<V>() }
+    }
+}
+
+impl<V: Clone + 'static, T: Clone + 'static> Comonad for Env<V, T> {
+    type Extracted = T;
+    type Duplicated<B: Clone + 'static> = Env<V, B>;
+
+    // extract : C(x) -> x
+    fn extract(&self) -> T {
+        self.value.clone()
+    }
+
+    // duplicate : C(x) -> C(C(x))
+    fn duplicate(self) -> Env<V, Self> {
+        Env { env: self.env.clone(), value: self }
+    }
+
+    /// Essentially an `fmap` which preserves the environment during computation, should it be
+    /// useful for another computation later
+    // extend : (C(x) -> y) -> C(x) -> C(y)
+    fn extend<B: Clone + 'static>(self, f: impl Fn(Self) -> B + 'static) -> Env<V, B> {
+        let env = self.env.clone();
+        Env { value: f(self), env }
+    }
+}
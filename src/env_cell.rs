@@ -0,0 +1,63 @@
+//! An interior-mutability cell for sharing a mutable environment across
+//! several `extend` steps, modeled on the `UnsafeWorldCell`-style escape
+//! hatch used in Bevy: a single owner holds the [`EnvCell`], and a
+//! comonad's `env` field borrows it (as an `Rc<EnvCell<V>>`, since a
+//! `Comonad` must be `'static` and so can't carry a plain borrow) so
+//! disjoint `extend` closures can observe updates without aliasing
+//! violations, instead of reaching for a `static mut`.
+//!
+//! The `get`/`set` contract only ever hands out owned copies of `V` and
+//! never lets a reference into the cell escape, which is exactly
+//! `std::cell::Cell`'s contract — so this is built on `Cell` rather than a
+//! hand-rolled `UnsafeCell`, keeping the crate's unsafe surface at zero.
+
+use std::cell::Cell;
+
+/// A shared, mutable environment cell.
+///
+/// The owner calls [`EnvCell::set`] between `extend` steps; anything
+/// holding a reference to the cell (e.g. via a comonad's `env: Rc<EnvCell<V>>`
+/// field) can call [`EnvCell::get`] to observe the current value.
+pub struct EnvCell<V>(Cell<V>);
+
+impl<V: Copy> EnvCell<V> {
+    pub fn new(value: V) -> Self {
+        EnvCell(Cell::new(value))
+    }
+
+    /// Read the current value.
+    pub fn get(&self) -> V {
+        self.0.get()
+    }
+
+    /// Overwrite the current value.
+    pub fn set(&self, value: V) {
+        self.0.set(value);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::rc::Rc;
+
+    use super::*;
+    use crate::comonad::Comonad;
+    use crate::env::Env;
+
+    #[test]
+    fn chained_extends_see_a_consistent_mutated_environment() {
+        let cell = Rc::new(EnvCell::new(1));
+        let env = Env { value: 10, env: cell.clone() };
+
+        let step1 = env.extend(|w: Env<Rc<EnvCell<i32>>, i32>| {
+            let seen = w.env.get();
+            w.env.set(seen + 1);
+            w.value + seen
+        });
+
+        let step2 = step1.extend(|w: Env<Rc<EnvCell<i32>>, i32>| w.value + w.env.get());
+
+        assert_eq!(cell.get(), 2);
+        assert_eq!(step2.value, 13);
+    }
+}
@@ -0,0 +1,113 @@
+//! Hand-rolled property tests that check the three comonad laws hold for
+//! every [`Comonad`] instance in this crate:
+//!
+//! - right identity: `w.extend(extract) == w`
+//! - left identity: `extract(w.extend(f)) == f(w)`
+//! - associativity: `w.extend(f).extend(g) == w.extend(|x| g(x.extend(f)))`
+//!
+//! Comparisons are made by sampling observable behavior (`extract`/`peek`/
+//! the traced `run` function) rather than by deriving `PartialEq` on every
+//! comonad, since `Store` and `Traced` hold closures that can't be compared
+//! directly.
+
+use rand::Rng;
+
+use crate::comonad::Comonad;
+use crate::env::Env;
+use crate::store::Store;
+use crate::traced::{Monoid, Traced};
+
+fn sample_envs() -> Vec<Env<i32, i32>> {
+    let mut rng = rand::rng();
+    (0..8).map(|_| Env { value: rng.random_range(-50..50), env: rng.random_range(-50..50) }).collect()
+}
+
+#[test]
+fn env_comonad_laws() {
+    let f = |w: Env<i32, i32>| w.value + w.env;
+    let g = |w: Env<i32, i32>| w.value * 2;
+
+    for w in sample_envs() {
+        // right identity
+        let right_identity = w.clone().extend(|x: Env<i32, i32>| x.extract());
+        assert_eq!(right_identity, w);
+
+        // left identity
+        assert_eq!(w.clone().extend(f).extract(), f(w.clone()));
+
+        // associativity
+        let lhs = w.clone().extend(f).extend(g);
+        let rhs = w.clone().extend(move |x: Env<i32, i32>| g(x.extend(f)));
+        assert_eq!(lhs, rhs);
+    }
+}
+
+fn sample_stores() -> Vec<Store<i32, i32>> {
+    (-3..=3).map(|focus| Store::new(|s: i32| s * s, focus)).collect()
+}
+
+#[test]
+fn store_comonad_laws() {
+    let f = |w: Store<i32, i32>| w.extract() + w.focus;
+    let g = |w: Store<i32, i32>| w.extract() * 2;
+
+    for w in sample_stores() {
+        // right identity
+        let right_identity = w.clone().extend(|x: Store<i32, i32>| x.extract());
+        for s in -3..=3 {
+            assert_eq!(right_identity.peek(s), w.peek(s));
+        }
+
+        // left identity
+        assert_eq!(w.clone().extend(f).extract(), f(w.clone()));
+
+        // associativity
+        let lhs = w.clone().extend(f).extend(g);
+        let rhs = w.clone().extend(move |x: Store<i32, i32>| g(x.extend(f)));
+        for s in -3..=3 {
+            assert_eq!(lhs.peek(s), rhs.peek(s));
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+struct Sum(i32);
+
+impl Monoid for Sum {
+    fn empty() -> Self {
+        Sum(0)
+    }
+
+    fn combine(&self, other: &Self) -> Self {
+        Sum(self.0 + other.0)
+    }
+}
+
+fn sample_traced() -> Vec<Traced<Sum, i32>> {
+    (0..4).map(|k| Traced::new(move |Sum(n)| n * 2 + k)).collect()
+}
+
+#[test]
+fn traced_comonad_laws() {
+    let f = |w: Traced<Sum, i32>| w.extract() + 1;
+    let g = |w: Traced<Sum, i32>| w.extract() * 3;
+    let points = [Sum(-2), Sum(-1), Sum(0), Sum(1), Sum(2)];
+
+    for w in sample_traced() {
+        // right identity
+        let right_identity = w.clone().extend(|x: Traced<Sum, i32>| x.extract());
+        for &m in &points {
+            assert_eq!((right_identity.run)(m), (w.run)(m));
+        }
+
+        // left identity
+        assert_eq!(w.clone().extend(f).extract(), f(w.clone()));
+
+        // associativity
+        let lhs = w.clone().extend(f).extend(g);
+        let rhs = w.clone().extend(move |x: Traced<Sum, i32>| g(x.extend(f)));
+        for &m in &points {
+            assert_eq!((lhs.run)(m), (rhs.run)(m));
+        }
+    }
+}
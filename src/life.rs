@@ -0,0 +1,94 @@
+//! Conway's Game of Life, implemented as an `extend` over the [`Store`] comonad.
+//!
+//! The board is a [`Store`] whose focus is the cell currently under
+//! consideration; a single `extend(life_rule)` reads every cell's
+//! neighborhood through the store's lookup function and produces the next
+//! generation in one shot.
+
+use crate::comonad::Comonad;
+use crate::store::Store;
+
+/// A grid coordinate.
+pub type Coord = (i32, i32);
+
+/// A Game of Life board: a lookup from coordinates to alive/dead, focused
+/// on the cell currently being computed.
+pub type Board = Store<Coord, bool>;
+
+/// The eight neighbors of a grid coordinate.
+fn neighbors((x, y): Coord) -> [Coord; 8] {
+    [
+        (x - 1, y - 1), (x, y - 1), (x + 1, y - 1),
+        (x - 1, y),                 (x + 1, y),
+        (x - 1, y + 1), (x, y + 1), (x + 1, y + 1),
+    ]
+}
+
+/// Build a `width` x `height` board from a row-major `cells` slice, where a
+/// lookup outside `[0, width) x [0, height)` wraps around, i.e. the board
+/// is a torus with no special-cased edges.
+pub fn wrapping_board(cells: Vec<bool>, width: i32, height: i32, focus: Coord) -> Board {
+    Store::new(
+        move |(x, y): Coord| {
+            let wx = x.rem_euclid(width);
+            let wy = y.rem_euclid(height);
+            cells[(wy * width + wx) as usize]
+        },
+        focus,
+    )
+}
+
+/// Conway's rule: a living cell survives with 2 or 3 living neighbors; a
+/// dead cell is born with exactly 3.
+pub fn life_rule(board: Board) -> bool {
+    let alive_neighbors = neighbors(board.focus).into_iter().filter(|&n| board.peek(n)).count();
+
+    matches!((board.extract(), alive_neighbors), (true, 2) | (true, 3) | (false, 3))
+}
+
+/// Advance the board one generation with a single `extend(life_rule)`, then
+/// materialize the result into a fresh `width` x `height` grid.
+///
+/// `board.extend(life_rule)` alone would return a `Store` whose lookup
+/// re-runs `life_rule` against the *previous* generation's lookup on every
+/// `peek` — chaining `step` without materializing would make each
+/// generation's lookup cost grow with the number of prior generations.
+/// Evaluating every cell once here keeps each `step` call's cost flat.
+pub fn step(board: Board, width: i32, height: i32) -> Board {
+    let next = board.extend(life_rule);
+    let cells = (0..height).flat_map(|y| (0..width).map(move |x| (x, y))).map(|coord| next.peek(coord)).collect();
+    wrapping_board(cells, width, height, next.focus)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn row(board: &Board, y: i32, width: i32) -> Vec<bool> {
+        (0..width).map(|x| board.peek((x, y))).collect()
+    }
+
+    #[test]
+    fn blinker_oscillates() {
+        // A vertical blinker in a 5x5 torus.
+        #[rustfmt::skip]
+        let cells = vec![
+            false, false, false, false, false,
+            false, false, true,  false, false,
+            false, false, true,  false, false,
+            false, false, true,  false, false,
+            false, false, false, false, false,
+        ];
+        let board = wrapping_board(cells, 5, 5, (0, 0));
+
+        let after_one = step(board, 5, 5);
+        assert_eq!(row(&after_one, 1, 5), vec![false, false, false, false, false]);
+        assert_eq!(row(&after_one, 2, 5), vec![false, true, true, true, false]);
+        assert_eq!(row(&after_one, 3, 5), vec![false, false, false, false, false]);
+
+        let after_two = step(after_one, 5, 5);
+        assert_eq!(row(&after_two, 1, 5), vec![false, false, true, false, false]);
+        assert_eq!(row(&after_two, 2, 5), vec![false, false, true, false, false]);
+        assert_eq!(row(&after_two, 3, 5), vec![false, false, true, false, false]);
+    }
+}
@@ -0,0 +1,65 @@
+//! A safe, re-entrant replacement for reaching into a `static mut` to model
+//! an ambient environment, modeled on the `environmental` crate.
+//!
+//! A thread-local slot holds the currently installed value for each
+//! environment type `V`. [`with_env`] installs a value for the dynamic
+//! extent of a closure, restoring whatever was installed before (even if
+//! the closure panics), and [`current_env`] reads the active value from
+//! anywhere inside that extent.
+
+use std::any::{Any, TypeId};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::marker::PhantomData;
+
+thread_local! {
+    static SCOPES: RefCell<HashMap<TypeId, Box<dyn Any>>> = RefCell::new(HashMap::new());
+}
+
+/// Restores whatever was installed for `V` before, dropped even on panic.
+struct Restore<V: 'static> {
+    previous: Option<Box<dyn Any>>,
+    _marker: PhantomData<V>,
+}
+
+impl<V: 'static> Drop for Restore<V> {
+    fn drop(&mut self) {
+        SCOPES.with(|scopes| {
+            let mut scopes = scopes.borrow_mut();
+            match self.previous.take() {
+                Some(previous) => {
+                    scopes.insert(TypeId::of::<V>(), previous);
+                }
+                None => {
+                    scopes.remove(&TypeId::of::<V>());
+                }
+            }
+        });
+    }
+}
+
+/// Install `value` as the active environment of type `V` for the dynamic
+/// extent of `f`, restoring the previous value (or absence of one) when
+/// `f` returns or unwinds.
+pub fn with_env<V: 'static, R>(value: V, f: impl FnOnce() -> R) -> R {
+    let previous = SCOPES.with(|scopes| scopes.borrow_mut().insert(TypeId::of::<V>(), Box::new(value)));
+    let _restore = Restore::<V> { previous, _marker: PhantomData };
+    f()
+}
+
+/// Read the environment value installed by the innermost enclosing
+/// [`with_env::<V, _>`] call.
+///
+/// # Panics
+///
+/// Panics if called outside of a matching `with_env::<V, _>` scope.
+pub fn current_env<V: Clone + 'static>() -> V {
+    SCOPES.with(|scopes| {
+        scopes
+            .borrow()
+            .get(&TypeId::of::<V>())
+            .and_then(|value| value.downcast_ref::<V>())
+            .cloned()
+            .expect("current_env::<V> called outside of a matching with_env::<V, _> scope")
+    })
+}
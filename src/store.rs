@@ -0,0 +1,62 @@
+//! The store comonad: a lookup function paired with a focus.
+
+use std::rc::Rc;
+
+use crate::comonad::Comonad;
+
+/// A function `S -> A` together with a focus `S` at which to read it.
+///
+/// `extract` evaluates the lookup at the current focus; `duplicate` hands
+/// back a store of stores, one refocused at each possible `S`.
+pub struct Store<S, A> {
+    pub lookup: Rc<dyn Fn(S) -> A>,
+    pub focus: S,
+}
+
+impl<S, A> Clone for Store<S, A>
+where
+    S: Clone,
+{
+    fn clone(&self) -> Self {
+        Store { lookup: self.lookup.clone(), focus: self.focus.clone() }
+    }
+}
+
+impl<S, A> Store<S, A> {
+    pub fn new(lookup: impl Fn(S) -> A + 'static, focus: S) -> Self {
+        Store { lookup: Rc::new(lookup), focus }
+    }
+
+    /// Read the lookup function at an arbitrary `S`, without moving the focus.
+    pub fn peek(&self, s: S) -> A {
+        (self.lookup)(s)
+    }
+}
+
+impl<S: Clone + 'static, A: Clone + 'static> Comonad for Store<S, A> {
+    type Extracted = A;
+    type Duplicated<B: Clone + 'static> = Store<S, B>;
+
+    // extract : C(x) -> x
+    fn extract(&self) -> A {
+        (self.lookup)(self.focus.clone())
+    }
+
+    // duplicate : C(x) -> C(C(x))
+    fn duplicate(self) -> Store<S, Self> {
+        let lookup = self.lookup.clone();
+        Store {
+            lookup: Rc::new(move |s: S| Store { lookup: lookup.clone(), focus: s }),
+            focus: self.focus,
+        }
+    }
+
+    // extend : (C(x) -> y) -> C(x) -> C(y)
+    fn extend<B: Clone + 'static>(self, f: impl Fn(Self) -> B + 'static) -> Store<S, B> {
+        let lookup = self.lookup.clone();
+        Store {
+            lookup: Rc::new(move |s: S| f(Store { lookup: lookup.clone(), focus: s })),
+            focus: self.focus,
+        }
+    }
+}
@@ -0,0 +1,62 @@
+//! The traced (a.k.a. co-writer) comonad: a function out of a monoid.
+
+use std::rc::Rc;
+
+use crate::comonad::Comonad;
+
+/// A monoid: a type with an associative `combine` and a neutral `empty`.
+pub trait Monoid: Clone {
+    /// The neutral element: `x.combine(&M::empty()) == x`.
+    fn empty() -> Self;
+
+    /// An associative combination of two monoid values.
+    fn combine(&self, other: &Self) -> Self;
+}
+
+/// A function `M -> A`, read at accumulated positions of the monoid `M`.
+///
+/// `extract` reads the function at `M::empty()`; `extend(f)` lets `f`
+/// observe the whole trace accumulated so far at every point.
+pub struct Traced<M, A> {
+    pub run: Rc<dyn Fn(M) -> A>,
+}
+
+impl<M, A> Clone for Traced<M, A> {
+    fn clone(&self) -> Self {
+        Traced { run: self.run.clone() }
+    }
+}
+
+impl<M, A> Traced<M, A> {
+    pub fn new(run: impl Fn(M) -> A + 'static) -> Self {
+        Traced { run: Rc::new(run) }
+    }
+}
+
+impl<M: Monoid + 'static, A: Clone + 'static> Comonad for Traced<M, A> {
+    type Extracted = A;
+    type Duplicated<B: Clone + 'static> = Traced<M, B>;
+
+    // extract : C(x) -> x
+    fn extract(&self) -> A {
+        (self.run)(M::empty())
+    }
+
+    // duplicate : C(x) -> C(C(x))
+    fn duplicate(self) -> Traced<M, Self> {
+        let run = self.run.clone();
+        Traced::new(move |m1: M| {
+            let run = run.clone();
+            Traced::new(move |m2: M| run(m1.combine(&m2)))
+        })
+    }
+
+    // extend : (C(x) -> y) -> C(x) -> C(y)
+    fn extend<B: Clone + 'static>(self, f: impl Fn(Self) -> B + 'static) -> Traced<M, B> {
+        let run = self.run.clone();
+        Traced::new(move |m: M| {
+            let run = run.clone();
+            f(Traced::new(move |m2: M| run(m.combine(&m2))))
+        })
+    }
+}